@@ -1,12 +1,20 @@
 // Learn more about Tauri commands at https://tauri.app/develop/calling-rust/
+use auto_launch::AutoLaunchBuilder;
 use chrono::{DateTime, Utc};
+use leptess::LepTess;
 use serde::{Deserialize, Serialize};
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions, SqliteRow};
+use sqlx::{Row, SqlitePool};
 use std::fs;
 use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{mpsc, Mutex, OnceCell};
+use uuid::Uuid;
 
 use screenshots::Screen;
-use screenshots::image::GenericImageView;
-use tauri::{AppHandle, Emitter, Listener};
+use screenshots::image::{GenericImageView, RgbaImage};
+use tauri::{AppHandle, Emitter, Manager};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct OcrResult {
@@ -17,13 +25,62 @@ pub struct OcrResult {
     pub confidence: f32,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AppSettings {
-    pub global_hotkey: String,
+    #[serde(default)]
+    pub hotkeys: HotkeysConfig,
     pub auto_copy_to_clipboard: bool,
     pub save_screenshots: bool,
     pub screenshots_dir: String,
     pub autostart: bool,
+    #[serde(default)]
+    pub start_minimized: bool,
+    #[serde(default = "default_ocr_language")]
+    pub default_language: String,
+}
+
+/// Fallback for `settings.json` files written before `default_language`
+/// existed, so loading them doesn't fail with a missing-field error.
+fn default_ocr_language() -> String {
+    "eng".to_string()
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HotkeyBinding {
+    pub keys: String,
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HotkeysConfig {
+    pub region_capture: HotkeyBinding,
+    pub fullscreen_capture: HotkeyBinding,
+    pub recapture_last: HotkeyBinding,
+}
+
+impl Default for HotkeysConfig {
+    fn default() -> Self {
+        Self {
+            region_capture: HotkeyBinding {
+                keys: "ctrl+shift+o".to_string(),
+                enabled: true,
+            },
+            fullscreen_capture: HotkeyBinding {
+                keys: "ctrl+shift+f".to_string(),
+                enabled: false,
+            },
+            recapture_last: HotkeyBinding {
+                keys: "ctrl+shift+r".to_string(),
+                enabled: false,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct HotkeyRegistrationError {
+    pub binding: String,
+    pub error: String,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -32,16 +89,43 @@ pub struct CaptureRegion {
     pub y: i32,
     pub width: u32,
     pub height: u32,
+    pub screen_id: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ScreenInfo {
+    pub id: u32,
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    pub scale_factor: f32,
+}
+
+impl From<&Screen> for ScreenInfo {
+    fn from(screen: &Screen) -> Self {
+        let info = screen.display_info;
+        Self {
+            id: info.id,
+            x: info.x,
+            y: info.y,
+            width: info.width,
+            height: info.height,
+            scale_factor: info.scale_factor,
+        }
+    }
 }
 
 impl Default for AppSettings {
     fn default() -> Self {
         Self {
-            global_hotkey: "ctrl+shift+o".to_string(),
+            hotkeys: HotkeysConfig::default(),
             auto_copy_to_clipboard: true,
             save_screenshots: true,
             screenshots_dir: get_default_screenshots_dir(),
             autostart: false,
+            start_minimized: false,
+            default_language: default_ocr_language(),
         }
     }
 }
@@ -68,127 +152,735 @@ async fn start_region_capture(app: AppHandle) -> Result<(), String> {
 }
 
 #[tauri::command]
-async fn capture_screenshot_region(region: Option<CaptureRegion>) -> Result<String, String> {
+async fn list_screens() -> Result<Vec<ScreenInfo>, String> {
     let screens = Screen::all().map_err(|e| format!("Failed to get screens: {}", e))?;
-    
+    Ok(screens.iter().map(ScreenInfo::from).collect())
+}
+
+/// Picks the screen to capture from: an explicit `screen_id` wins, otherwise the
+/// screen whose bounds contain the region's origin (in virtual-desktop space) is
+/// used, falling back to the primary screen.
+fn select_screen<'a>(screens: &'a [Screen], region: Option<&CaptureRegion>) -> Result<&'a Screen, String> {
     if screens.is_empty() {
         return Err("No screens found".to_string());
     }
 
-    // Use the primary screen for now
-    let screen = &screens[0];
+    if let Some(region) = region {
+        if let Some(screen_id) = region.screen_id {
+            return screens
+                .iter()
+                .find(|s| s.display_info.id == screen_id)
+                .ok_or_else(|| format!("No screen found with id {}", screen_id));
+        }
+
+        if let Some(screen) = screens.iter().find(|s| {
+            let info = s.display_info;
+            region.x >= info.x
+                && region.y >= info.y
+                && region.x < info.x + info.width as i32
+                && region.y < info.y + info.height as i32
+        }) {
+            return Ok(screen);
+        }
+    }
+
+    Ok(screens
+        .iter()
+        .find(|s| s.display_info.is_primary)
+        .unwrap_or(&screens[0]))
+}
+
+/// Crops `image`, captured from `screen`, to `region`, translating from
+/// virtual-desktop (global) coordinates into the screen's local space.
+fn crop_to_region(image: RgbaImage, screen: &Screen, region: &CaptureRegion) -> Result<RgbaImage, String> {
+    let info = screen.display_info;
+    let width = image.width();
+    let height = image.height();
+
+    let x = (region.x - info.x).max(0) as u32;
+    let y = (region.y - info.y).max(0) as u32;
+    let crop_width = region.width.min(width.saturating_sub(x));
+    let crop_height = region.height.min(height.saturating_sub(y));
+
+    if crop_width == 0 || crop_height == 0 {
+        return Err("Invalid capture region".to_string());
+    }
+
+    Ok(image.view(x, y, crop_width, crop_height).to_image())
+}
+
+/// Captures the screen selected by `region` (or the primary screen when none
+/// is given) and crops to the region if one was specified.
+fn capture_region_image(region: Option<&CaptureRegion>) -> Result<RgbaImage, String> {
+    let screens = Screen::all().map_err(|e| format!("Failed to get screens: {}", e))?;
+    let screen = select_screen(&screens, region)?;
     let image = screen.capture().map_err(|e| format!("Failed to capture screen: {}", e))?;
-    
-    // Crop the image if a region is specified
-    let final_image = if let Some(region) = region {
-        let width = image.width();
-        let height = image.height();
-        
-        // Ensure region is within bounds
-        let x = region.x.max(0) as u32;
-        let y = region.y.max(0) as u32;
-        let crop_width = region.width.min(width.saturating_sub(x));
-        let crop_height = region.height.min(height.saturating_sub(y));
-        
-        if crop_width == 0 || crop_height == 0 {
-            return Err("Invalid capture region".to_string());
+
+    match region {
+        Some(region) => crop_to_region(image, screen, region),
+        None => Ok(image),
+    }
+}
+
+#[cfg(test)]
+mod capture_region_tests {
+    use super::*;
+    use screenshots::DisplayInfo;
+
+    fn screen_at(id: u32, x: i32, y: i32, width: u32, height: u32, is_primary: bool) -> Screen {
+        Screen {
+            display_info: DisplayInfo {
+                id,
+                x,
+                y,
+                width,
+                height,
+                rotation: 0.0,
+                scale_factor: 1.0,
+                is_primary,
+            },
         }
-        
-        image.view(x, y, crop_width, crop_height).to_image()
-    } else {
-        image
-    };
-    
+    }
+
+    #[test]
+    fn select_screen_by_id_wins_over_region_origin() {
+        let screens = vec![
+            screen_at(0, 0, 0, 1920, 1080, true),
+            screen_at(1, 1920, 0, 1920, 1080, false),
+        ];
+        let region = CaptureRegion {
+            x: 10,
+            y: 10,
+            width: 100,
+            height: 100,
+            screen_id: Some(1),
+        };
+
+        let selected = select_screen(&screens, Some(&region)).unwrap();
+        assert_eq!(selected.display_info.id, 1);
+    }
+
+    #[test]
+    fn select_screen_falls_back_to_region_origin_containment() {
+        let screens = vec![
+            screen_at(0, 0, 0, 1920, 1080, true),
+            screen_at(1, 1920, 0, 1920, 1080, false),
+        ];
+        let region = CaptureRegion {
+            x: 2000,
+            y: 50,
+            width: 100,
+            height: 100,
+            screen_id: None,
+        };
+
+        let selected = select_screen(&screens, Some(&region)).unwrap();
+        assert_eq!(selected.display_info.id, 1);
+    }
+
+    #[test]
+    fn select_screen_defaults_to_primary_without_region() {
+        let screens = vec![
+            screen_at(0, 0, 0, 1920, 1080, true),
+            screen_at(1, 1920, 0, 1920, 1080, false),
+        ];
+
+        let selected = select_screen(&screens, None).unwrap();
+        assert_eq!(selected.display_info.id, 0);
+    }
+
+    #[test]
+    fn select_screen_defaults_to_primary_even_when_not_first_in_list() {
+        // `Screen::all()` order is OS-dependent, so the primary display may not
+        // be at index 0 — the fallback must search `is_primary`, not index.
+        let screens = vec![
+            screen_at(0, 0, 0, 1920, 1080, false),
+            screen_at(1, 1920, 0, 1920, 1080, true),
+        ];
+
+        let selected = select_screen(&screens, None).unwrap();
+        assert_eq!(selected.display_info.id, 1);
+    }
+
+    #[test]
+    fn select_screen_errors_on_empty_screen_list() {
+        let screens: Vec<Screen> = Vec::new();
+        assert!(select_screen(&screens, None).is_err());
+    }
+
+    #[test]
+    fn crop_to_region_translates_global_coordinates_to_local_space() {
+        let screen = screen_at(1, 1920, 0, 1920, 1080, false);
+        let image = RgbaImage::new(1920, 1080);
+        let region = CaptureRegion {
+            x: 1930,
+            y: 20,
+            width: 50,
+            height: 60,
+            screen_id: Some(1),
+        };
+
+        let cropped = crop_to_region(image, &screen, &region).unwrap();
+        assert_eq!(cropped.width(), 50);
+        assert_eq!(cropped.height(), 60);
+    }
+
+    #[test]
+    fn crop_to_region_rejects_out_of_bounds_region() {
+        let screen = screen_at(0, 0, 0, 100, 100, true);
+        let image = RgbaImage::new(100, 100);
+        let region = CaptureRegion {
+            x: 200,
+            y: 200,
+            width: 50,
+            height: 50,
+            screen_id: None,
+        };
+
+        assert!(crop_to_region(image, &screen, &region).is_err());
+    }
+}
+
+#[tauri::command]
+async fn capture_screenshot_region(region: Option<CaptureRegion>) -> Result<String, String> {
+    let final_image = capture_region_image(region.as_ref())?;
+
     // Save the screenshot
     let data_dir = get_app_data_dir()?;
     let screenshots_dir = data_dir.join("screenshots");
     fs::create_dir_all(&screenshots_dir)
         .map_err(|e| format!("Failed to create screenshots directory: {}", e))?;
-    
+
     let timestamp = Utc::now();
     let filename = format!("screenshot_{}.png", timestamp.format("%Y%m%d_%H%M%S"));
     let filepath = screenshots_dir.join(&filename);
-    
-    final_image.save(&filepath)
+
+    final_image
+        .save(&filepath)
         .map_err(|e| format!("Failed to save screenshot: {}", e))?;
-    
+
     Ok(filepath.to_string_lossy().to_string())
 }
 
-#[tauri::command]
-async fn save_ocr_result(result: OcrResult) -> Result<(), String> {
+/// Stop flag for whichever region recording is currently in flight, if any.
+static ACTIVE_RECORDING_STOP: Mutex<Option<Arc<Mutex<bool>>>> = Mutex::const_new(None);
+
+/// Number of frames a recording at `fps` for `duration_ms` should grab,
+/// always at least one even when the duration rounds down to zero frames.
+fn recording_frame_count(fps: u32, duration_ms: u32) -> u64 {
+    ((duration_ms as u64 * fps as u64) / 1000).max(1)
+}
+
+/// Grabs frames of `region` at `fps` for `duration_ms`, saving each as a
+/// numbered PNG, until the duration elapses or `stop_flag` is set.
+async fn record_region_frames(
+    region: CaptureRegion,
+    fps: u32,
+    duration_ms: u32,
+    stop_flag: Arc<Mutex<bool>>,
+) -> Result<Vec<String>, String> {
     let data_dir = get_app_data_dir()?;
-    let results_file = data_dir.join("ocr_results.json");
-    
-    // Load existing results
-    let mut results: Vec<OcrResult> = if results_file.exists() {
-        let content = fs::read_to_string(&results_file)
-            .map_err(|e| format!("Failed to read results file: {}", e))?;
-        serde_json::from_str(&content).unwrap_or_default()
-    } else {
-        Vec::new()
-    };
-    
-    // Add new result
-    results.push(result);
-    
-    // Save back to file
-    let json = serde_json::to_string_pretty(&results)
-        .map_err(|e| format!("Failed to serialize results: {}", e))?;
-    
-    fs::write(&results_file, json)
-        .map_err(|e| format!("Failed to write results file: {}", e))?;
-    
+    let recordings_dir = data_dir.join("screenshots").join("recordings");
+    fs::create_dir_all(&recordings_dir)
+        .map_err(|e| format!("Failed to create recordings directory: {}", e))?;
+
+    let frame_interval = Duration::from_millis(1000 / fps as u64);
+    let total_frames = recording_frame_count(fps, duration_ms);
+    // Uuid rather than a timestamp so two recordings starting in the same
+    // second never collide on their frame filenames.
+    let session_id = Uuid::new_v4().to_string();
+
+    let mut frame_paths = Vec::new();
+
+    for frame_index in 0..total_frames {
+        if *stop_flag.lock().await {
+            break;
+        }
+
+        let frame = capture_region_image(Some(&region))?;
+
+        let filename = format!("recording_{}_{:04}.png", session_id, frame_index);
+        let filepath = recordings_dir.join(&filename);
+        frame
+            .save(&filepath)
+            .map_err(|e| format!("Failed to save frame {}: {}", frame_index, e))?;
+        frame_paths.push(filepath.to_string_lossy().to_string());
+
+        tokio::time::sleep(frame_interval).await;
+    }
+
+    Ok(frame_paths)
+}
+
+#[cfg(test)]
+mod region_recording_tests {
+    use super::*;
+
+    #[test]
+    fn recording_frame_count_rounds_down_to_whole_frames() {
+        assert_eq!(recording_frame_count(10, 1000), 10);
+        assert_eq!(recording_frame_count(2, 2500), 5);
+        // 3 fps for 1500ms is 4.5 frames.
+        assert_eq!(recording_frame_count(3, 1500), 4);
+    }
+
+    #[test]
+    fn recording_frame_count_floors_at_one() {
+        // 5 fps for 50ms is 0.25 frames, which would otherwise record nothing.
+        assert_eq!(recording_frame_count(5, 50), 1);
+    }
+
+    #[tokio::test]
+    async fn record_region_frames_stops_immediately_when_flag_already_set() {
+        let region = CaptureRegion {
+            x: 0,
+            y: 0,
+            width: 10,
+            height: 10,
+            screen_id: None,
+        };
+        let stop_flag = Arc::new(Mutex::new(true));
+
+        let frames = record_region_frames(region, 10, 1000, stop_flag)
+            .await
+            .expect("recording should stop cleanly rather than error");
+
+        assert!(frames.is_empty());
+    }
+}
+
+#[tauri::command]
+async fn start_region_recording(
+    region: CaptureRegion,
+    fps: u32,
+    duration_ms: u32,
+) -> Result<Vec<String>, String> {
+    if fps == 0 {
+        return Err("fps must be greater than zero".to_string());
+    }
+
+    let stop_flag = Arc::new(Mutex::new(false));
+    {
+        let mut active = ACTIVE_RECORDING_STOP.lock().await;
+        if active.is_some() {
+            return Err("recording already in progress".to_string());
+        }
+        *active = Some(stop_flag.clone());
+    }
+
+    let join_result =
+        tauri::async_runtime::spawn(record_region_frames(region, fps, duration_ms, stop_flag.clone())).await;
+
+    // Clear the slot on every exit path, including a panic in the recording
+    // task — otherwise a panicked recording leaves the app permanently unable
+    // to start a new one. Only clear it if it still points at the flag we
+    // registered above, in case the slot was ever repurposed.
+    {
+        let mut active = ACTIVE_RECORDING_STOP.lock().await;
+        if active.as_ref().is_some_and(|current| Arc::ptr_eq(current, &stop_flag)) {
+            *active = None;
+        }
+    }
+
+    join_result.map_err(|e| format!("Recording task panicked: {}", e))?
+}
+
+#[tauri::command]
+async fn stop_region_recording() -> Result<(), String> {
+    if let Some(stop_flag) = ACTIVE_RECORDING_STOP.lock().await.as_ref() {
+        *stop_flag.lock().await = true;
+    }
+
     Ok(())
 }
 
 #[tauri::command]
-async fn get_ocr_history() -> Result<Vec<OcrResult>, String> {
+async fn run_ocr(image_path: String, lang: Option<String>) -> Result<OcrResult, String> {
+    let settings = get_settings().await?;
+    let lang = lang.unwrap_or(settings.default_language);
+
+    let (text, confidence) = tauri::async_runtime::spawn_blocking(move || {
+        let mut ocr = LepTess::new(None, &lang)
+            .map_err(|e| format!("Failed to initialize OCR engine for '{}': {}", lang, e))?;
+        ocr.set_image(&image_path)
+            .map_err(|e| format!("Failed to load image '{}': {}", image_path, e))?;
+
+        let text = ocr
+            .get_utf8_text()
+            .map_err(|e| format!("Failed to recognize text: {}", e))?;
+        let confidence = ocr.mean_text_conf() as f32 / 100.0;
+
+        Ok::<(String, f32), String>((text, confidence))
+    })
+    .await
+    .map_err(|e| format!("OCR task panicked: {}", e))??;
+
+    Ok(OcrResult {
+        id: Uuid::new_v4().to_string(),
+        text,
+        timestamp: Utc::now(),
+        screenshot_path: image_path,
+        confidence,
+    })
+}
+
+#[tauri::command]
+async fn capture_and_recognize(
+    app: AppHandle,
+    region: Option<CaptureRegion>,
+    lang: Option<String>,
+) -> Result<OcrResult, String> {
+    let screenshot_path = capture_screenshot_region(region).await?;
+    let result = run_ocr(screenshot_path, lang).await?;
+
+    // Persist the result before touching the clipboard, so a locked/unavailable
+    // clipboard doesn't discard an otherwise-successful capture from history.
+    save_ocr_result(OcrResult {
+        id: result.id.clone(),
+        text: result.text.clone(),
+        timestamp: result.timestamp,
+        screenshot_path: result.screenshot_path.clone(),
+        confidence: result.confidence,
+    })
+    .await?;
+
+    let settings = get_settings().await?;
+    if settings.auto_copy_to_clipboard {
+        use tauri_plugin_clipboard_manager::ClipboardExt;
+        if let Err(e) = app.clipboard().write_text(result.text.clone()) {
+            eprintln!("Failed to copy result to clipboard: {}", e);
+        }
+    }
+
+    Ok(result)
+}
+
+static DB_POOL: OnceCell<SqlitePool> = OnceCell::const_new();
+
+/// Lazily opens (and migrates) the `ocr_results` SQLite database, caching the
+/// pool for the lifetime of the process.
+async fn get_db_pool() -> Result<&'static SqlitePool, String> {
+    DB_POOL
+        .get_or_try_init(|| async {
+            let data_dir = get_app_data_dir()?;
+            fs::create_dir_all(&data_dir)
+                .map_err(|e| format!("Failed to create data directory: {}", e))?;
+
+            let db_path = data_dir.join("ocr_results.db");
+            let options = SqliteConnectOptions::new()
+                .filename(&db_path)
+                .create_if_missing(true);
+
+            let pool = SqlitePoolOptions::new()
+                .max_connections(5)
+                .connect_with(options)
+                .await
+                .map_err(|e| format!("Failed to open OCR history database: {}", e))?;
+
+            init_db_schema(&pool).await?;
+            migrate_legacy_json_history(&pool).await?;
+
+            Ok(pool)
+        })
+        .await
+}
+
+async fn init_db_schema(pool: &SqlitePool) -> Result<(), String> {
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS ocr_results (
+            id TEXT PRIMARY KEY,
+            text TEXT NOT NULL,
+            timestamp TEXT NOT NULL,
+            screenshot_path TEXT NOT NULL,
+            confidence REAL NOT NULL
+        )",
+    )
+    .execute(pool)
+    .await
+    .map_err(|e| format!("Failed to create ocr_results table: {}", e))?;
+
+    sqlx::query(
+        "CREATE VIRTUAL TABLE IF NOT EXISTS ocr_results_fts USING fts5(
+            text, content='ocr_results', content_rowid='rowid'
+        )",
+    )
+    .execute(pool)
+    .await
+    .map_err(|e| format!("Failed to create ocr_results_fts table: {}", e))?;
+
+    // Keep the FTS index in sync with inserts made through `insert_ocr_result`.
+    sqlx::query(
+        "CREATE TRIGGER IF NOT EXISTS ocr_results_ai AFTER INSERT ON ocr_results BEGIN
+            INSERT INTO ocr_results_fts(rowid, text) VALUES (new.rowid, new.text);
+        END",
+    )
+    .execute(pool)
+    .await
+    .map_err(|e| format!("Failed to create ocr_results_fts trigger: {}", e))?;
+
+    Ok(())
+}
+
+/// One-time import of a pre-existing `ocr_results.json` history file, run the
+/// first time the database is created on a machine that already had captures.
+async fn migrate_legacy_json_history(pool: &SqlitePool) -> Result<(), String> {
     let data_dir = get_app_data_dir()?;
-    let results_file = data_dir.join("ocr_results.json");
-    
-    if !results_file.exists() {
-        return Ok(Vec::new());
+    let legacy_file = data_dir.join("ocr_results.json");
+
+    if !legacy_file.exists() {
+        return Ok(());
     }
-    
-    let content = fs::read_to_string(&results_file)
-        .map_err(|e| format!("Failed to read results file: {}", e))?;
-    
-    let results: Vec<OcrResult> = serde_json::from_str(&content)
-        .map_err(|e| format!("Failed to parse results file: {}", e))?;
-    
-    Ok(results)
+
+    let existing: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM ocr_results")
+        .fetch_one(pool)
+        .await
+        .map_err(|e| format!("Failed to count existing OCR results: {}", e))?;
+
+    if existing > 0 {
+        return Ok(());
+    }
+
+    let content = fs::read_to_string(&legacy_file)
+        .map_err(|e| format!("Failed to read legacy results file: {}", e))?;
+    let results: Vec<OcrResult> = serde_json::from_str(&content).unwrap_or_default();
+
+    for result in &results {
+        insert_ocr_result(pool, result).await?;
+    }
+
+    let _ = fs::rename(&legacy_file, legacy_file.with_extension("json.migrated"));
+
+    Ok(())
+}
+
+async fn insert_ocr_result(pool: &SqlitePool, result: &OcrResult) -> Result<(), String> {
+    sqlx::query(
+        "INSERT INTO ocr_results (id, text, timestamp, screenshot_path, confidence)
+         VALUES (?, ?, ?, ?, ?)",
+    )
+    .bind(&result.id)
+    .bind(&result.text)
+    .bind(result.timestamp.to_rfc3339())
+    .bind(&result.screenshot_path)
+    .bind(result.confidence)
+    .execute(pool)
+    .await
+    .map_err(|e| format!("Failed to insert OCR result: {}", e))?;
+
+    Ok(())
+}
+
+fn row_to_ocr_result(row: &SqliteRow) -> Result<OcrResult, String> {
+    let timestamp: String = row.try_get("timestamp").map_err(|e| e.to_string())?;
+
+    Ok(OcrResult {
+        id: row.try_get("id").map_err(|e| e.to_string())?,
+        text: row.try_get("text").map_err(|e| e.to_string())?,
+        timestamp: DateTime::parse_from_rfc3339(&timestamp)
+            .map_err(|e| format!("Failed to parse stored timestamp: {}", e))?
+            .with_timezone(&Utc),
+        screenshot_path: row.try_get("screenshot_path").map_err(|e| e.to_string())?,
+        confidence: row.try_get("confidence").map_err(|e| e.to_string())?,
+    })
 }
 
 #[tauri::command]
-async fn get_settings() -> Result<AppSettings, String> {
+async fn save_ocr_result(result: OcrResult) -> Result<(), String> {
+    let pool = get_db_pool().await?;
+    insert_ocr_result(pool, &result).await
+}
+
+#[tauri::command]
+async fn get_ocr_history(limit: Option<u32>, offset: Option<u32>) -> Result<Vec<OcrResult>, String> {
+    let pool = get_db_pool().await?;
+    let limit = limit.unwrap_or(50);
+    let offset = offset.unwrap_or(0);
+
+    let rows = sqlx::query(
+        "SELECT id, text, timestamp, screenshot_path, confidence
+         FROM ocr_results
+         ORDER BY timestamp DESC
+         LIMIT ? OFFSET ?",
+    )
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Failed to load OCR history: {}", e))?;
+
+    rows.iter().map(row_to_ocr_result).collect()
+}
+
+#[tauri::command]
+async fn search_ocr_history(query: String, limit: u32, offset: u32) -> Result<Vec<OcrResult>, String> {
+    let pool = get_db_pool().await?;
+
+    let rows = sqlx::query(
+        "SELECT r.id, snippet(ocr_results_fts, 0, '[', ']', '...', 10) AS text,
+                r.timestamp, r.screenshot_path, r.confidence
+         FROM ocr_results_fts
+         JOIN ocr_results r ON r.rowid = ocr_results_fts.rowid
+         WHERE ocr_results_fts MATCH ?
+         ORDER BY rank
+         LIMIT ? OFFSET ?",
+    )
+    .bind(&query)
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Failed to search OCR history for '{}': {}", query, e))?;
+
+    rows.iter().map(row_to_ocr_result).collect()
+}
+
+static SETTINGS_STATE: OnceCell<Arc<Mutex<AppSettings>>> = OnceCell::const_new();
+static SETTINGS_SIGNAL: OnceCell<mpsc::UnboundedSender<()>> = OnceCell::const_new();
+
+const SETTINGS_DEBOUNCE: Duration = Duration::from_millis(500);
+
+async fn settings_state() -> Result<&'static Arc<Mutex<AppSettings>>, String> {
+    SETTINGS_STATE
+        .get_or_try_init(|| async { Ok(Arc::new(Mutex::new(load_settings_from_disk()?))) })
+        .await
+}
+
+fn load_settings_from_disk() -> Result<AppSettings, String> {
     let data_dir = get_app_data_dir()?;
     let settings_file = data_dir.join("settings.json");
-    
+
     if !settings_file.exists() {
         return Ok(AppSettings::default());
     }
-    
+
     let content = fs::read_to_string(&settings_file)
         .map_err(|e| format!("Failed to read settings file: {}", e))?;
-    
-    let settings: AppSettings = serde_json::from_str(&content)
-        .map_err(|e| format!("Failed to parse settings file: {}", e))?;
-    
-    Ok(settings)
+
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse settings file: {}", e))
 }
 
-#[tauri::command]
-async fn save_settings(settings: AppSettings) -> Result<(), String> {
+fn flush_settings_to_disk(settings: &AppSettings) -> Result<(), String> {
     let data_dir = get_app_data_dir()?;
     let settings_file = data_dir.join("settings.json");
-    
-    let json = serde_json::to_string_pretty(&settings)
+
+    let json = serde_json::to_string_pretty(settings)
         .map_err(|e| format!("Failed to serialize settings: {}", e))?;
-    
-    fs::write(&settings_file, json)
-        .map_err(|e| format!("Failed to write settings file: {}", e))?;
-    
+
+    fs::write(&settings_file, json).map_err(|e| format!("Failed to write settings file: {}", e))
+}
+
+/// Background task that owns the debounced write-back of `SETTINGS_STATE` to
+/// `settings.json`. Signals coalesce within `SETTINGS_DEBOUNCE` so rapid
+/// successive `save_settings` calls result in a single flush, after which
+/// hotkeys/autostart are reconciled on the first flush and afterwards only if
+/// those fields changed since the previous flush.
+fn spawn_settings_writer(app: AppHandle) {
+    let (tx, mut rx) = mpsc::unbounded_channel::<()>();
+    let _ = SETTINGS_SIGNAL.set(tx);
+
+    tauri::async_runtime::spawn(async move {
+        let mut previous: Option<AppSettings> = None;
+
+        while rx.recv().await.is_some() {
+            // Coalesce any further signals that arrive within the debounce window.
+            while tokio::time::timeout(SETTINGS_DEBOUNCE, rx.recv())
+                .await
+                .is_ok_and(|signal| signal.is_some())
+            {}
+
+            let state = match settings_state().await {
+                Ok(state) => state,
+                Err(e) => {
+                    eprintln!("Failed to access settings state: {}", e);
+                    continue;
+                }
+            };
+            let current = state.lock().await.clone();
+
+            if let Err(e) = flush_settings_to_disk(&current) {
+                eprintln!("Failed to persist settings: {}", e);
+                continue;
+            }
+
+            let _ = app.emit("settings-changed", &current);
+
+            let hotkeys_changed = match &previous {
+                Some(p) => p.hotkeys != current.hotkeys,
+                None => true,
+            };
+            if hotkeys_changed {
+                match register_hotkeys(app.clone(), current.hotkeys.clone()).await {
+                    Ok(errors) => {
+                        for err in errors {
+                            eprintln!("Failed to register hotkey '{}': {}", err.binding, err.error);
+                        }
+                    }
+                    Err(e) => eprintln!("Failed to register hotkeys: {}", e),
+                }
+            }
+
+            let autostart_changed = match &previous {
+                Some(p) => p.autostart != current.autostart,
+                None => true,
+            };
+            if autostart_changed {
+                if let Err(e) = reconcile_autostart(&current) {
+                    eprintln!("Failed to reconcile autostart: {}", e);
+                }
+            }
+
+            previous = Some(current);
+        }
+    });
+}
+
+#[tauri::command]
+async fn get_settings() -> Result<AppSettings, String> {
+    let state = settings_state().await?;
+    Ok(state.lock().await.clone())
+}
+
+#[tauri::command]
+async fn save_settings(settings: AppSettings) -> Result<(), String> {
+    let state = settings_state().await?;
+    *state.lock().await = settings;
+
+    let tx = SETTINGS_SIGNAL
+        .get()
+        .ok_or("Settings writer is not running")?;
+    tx.send(())
+        .map_err(|e| format!("Failed to signal settings writer: {}", e))
+}
+
+fn build_auto_launch() -> Result<auto_launch::AutoLaunch, String> {
+    let exe_path = std::env::current_exe()
+        .map_err(|e| format!("Failed to get current executable path: {}", e))?;
+
+    AutoLaunchBuilder::new()
+        .set_app_name("InstantText OCR")
+        .set_app_path(&exe_path.to_string_lossy())
+        .set_args(&["--minimized"])
+        .build()
+        .map_err(|e| format!("Failed to configure autostart: {}", e))
+}
+
+/// Brings the OS autostart registration in line with the saved `autostart` setting.
+fn reconcile_autostart(settings: &AppSettings) -> Result<(), String> {
+    let auto_launch = build_auto_launch()?;
+    let is_enabled = auto_launch
+        .is_enabled()
+        .map_err(|e| format!("Failed to query autostart state: {}", e))?;
+
+    if settings.autostart && !is_enabled {
+        auto_launch
+            .enable()
+            .map_err(|e| format!("Failed to enable autostart: {}", e))?;
+    } else if !settings.autostart && is_enabled {
+        auto_launch
+            .disable()
+            .map_err(|e| format!("Failed to disable autostart: {}", e))?;
+    }
+
     Ok(())
 }
 
@@ -206,21 +898,69 @@ async fn ensure_directories() -> Result<(), String> {
 }
 
 #[tauri::command]
-async fn register_global_hotkey(app: AppHandle, hotkey: String) -> Result<(), String> {
-    use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut};
-    
-    // First unregister any existing hotkey
-    let _ = app.global_shortcut().unregister_all();
-    
-    // Parse the hotkey string into a Shortcut
-    let shortcut: Shortcut = hotkey.parse()
-        .map_err(|e| format!("Failed to parse hotkey '{}': {}", hotkey, e))?;
-    
-    // Register the new hotkey
+async fn register_hotkeys(
+    app: AppHandle,
+    hotkeys: HotkeysConfig,
+) -> Result<Vec<HotkeyRegistrationError>, String> {
+    use tauri_plugin_global_shortcut::GlobalShortcutExt;
+
+    // Clear everything first so a disabled or re-bound shortcut doesn't linger.
     app.global_shortcut()
-        .register(shortcut)
-        .map_err(|e| format!("Failed to register hotkey: {}", e))?;
-    
+        .unregister_all()
+        .map_err(|e| format!("Failed to clear existing hotkeys: {}", e))?;
+
+    let bindings: [(&str, &HotkeyBinding, &'static str); 3] = [
+        (
+            "region_capture",
+            &hotkeys.region_capture,
+            "region-capture-triggered",
+        ),
+        (
+            "fullscreen_capture",
+            &hotkeys.fullscreen_capture,
+            "fullscreen-capture-triggered",
+        ),
+        (
+            "recapture_last",
+            &hotkeys.recapture_last,
+            "recapture-last-triggered",
+        ),
+    ];
+
+    let mut errors = Vec::new();
+    for (name, binding, event_name) in bindings {
+        if !binding.enabled {
+            continue;
+        }
+
+        if let Err(e) = register_one_hotkey(&app, &binding.keys, event_name) {
+            errors.push(HotkeyRegistrationError {
+                binding: name.to_string(),
+                error: e,
+            });
+        }
+    }
+
+    Ok(errors)
+}
+
+/// Parses and registers a single hotkey, wiring it to emit `event_name` on press.
+fn register_one_hotkey(app: &AppHandle, keys: &str, event_name: &'static str) -> Result<(), String> {
+    use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
+
+    let shortcut: Shortcut = keys
+        .parse()
+        .map_err(|e| format!("Failed to parse hotkey '{}': {}", keys, e))?;
+
+    let app_handle = app.clone();
+    app.global_shortcut()
+        .on_shortcut(shortcut, move |_app, _shortcut, event| {
+            if matches!(event.state(), ShortcutState::Pressed) {
+                let _ = app_handle.emit(event_name, {});
+            }
+        })
+        .map_err(|e| format!("Failed to register hotkey '{}': {}", keys, e))?;
+
     Ok(())
 }
 
@@ -252,32 +992,58 @@ pub fn run() {
         .invoke_handler(tauri::generate_handler![
             greet,
             start_region_capture,
+            list_screens,
             capture_screenshot_region,
+            start_region_recording,
+            stop_region_recording,
+            run_ocr,
+            capture_and_recognize,
             save_ocr_result,
             get_ocr_history,
+            search_ocr_history,
             get_settings,
             save_settings,
             ensure_directories,
-            register_global_hotkey
+            register_hotkeys
         ])
         .setup(|app| {
             let app_handle = app.handle().clone();
-            
-            // Set up global shortcut event listener
-            let app_handle_for_listener = app_handle.clone();
-            app.listen("shortcut", move |_event| {
-                let _ = app_handle_for_listener.emit("hotkey-pressed", {});
-            });
-            
+
+            // Start the debounced settings writer before anything can signal it
+            spawn_settings_writer(app_handle.clone());
+
+            // `auto_launch` passes this when the OS starts us on login
+            let launched_minimized = std::env::args().any(|arg| arg == "--minimized");
+
             // Initialize app directories on startup
             tauri::async_runtime::spawn(async move {
                 if let Err(e) = ensure_directories().await {
                     eprintln!("Failed to initialize directories: {}", e);
                 }
-                
-                // Register default global hotkey
-                if let Err(e) = register_global_hotkey(app_handle, "ctrl+shift+o".to_string()).await {
-                    eprintln!("Failed to register global hotkey: {}", e);
+
+                let settings = get_settings().await.unwrap_or_default();
+
+                // Reconcile OS autostart registration against the saved setting
+                if let Err(e) = reconcile_autostart(&settings) {
+                    eprintln!("Failed to reconcile autostart: {}", e);
+                }
+
+                // Register the enabled hotkey bindings
+                match register_hotkeys(app_handle.clone(), settings.hotkeys).await {
+                    Ok(errors) => {
+                        for err in errors {
+                            eprintln!("Failed to register hotkey '{}': {}", err.binding, err.error);
+                        }
+                    }
+                    Err(e) => eprintln!("Failed to register hotkeys: {}", e),
+                }
+
+                // Come up hidden to the tray when launched minimized, either via the
+                // autostart launch arg or because the user asked for it explicitly.
+                if launched_minimized || settings.start_minimized {
+                    if let Some(window) = app_handle.get_webview_window("main") {
+                        let _ = window.hide();
+                    }
                 }
             });
             Ok(())